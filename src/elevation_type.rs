@@ -0,0 +1,14 @@
+/// The kind of UAC elevation behind a token, as reported by
+/// `GetTokenInformation(TokenElevationType)`.
+///
+/// This is a finer-grained signal than a plain elevated/not-elevated bool:
+/// it tells you *why* a process is (or isn't) elevated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ElevationType {
+    /// UAC is disabled, or the account has no split token (e.g. the built-in Administrator).
+    Default,
+    /// Running with a full, elevated token.
+    Full,
+    /// Running de-elevated; a linked admin token is available and could be elevated to via `runas`.
+    Limited,
+}
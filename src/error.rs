@@ -0,0 +1,69 @@
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ErrorType {
+    None,
+    Unknown,
+
+    OpenProcessFailed,
+    OpenProcessTokenFailed,
+    GetTokenInformationFailed,
+    DuplicateTokenFailed,
+    ShellExecuteFailed,
+}
+
+/// Carries whatever the platform actually gave us: a Windows `GetLastError()`
+/// code or a Unix `errno`, so callers don't have to guess which one applies.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ErrorCode {
+    None,
+    Sys(u32),
+    Errno(i32),
+}
+
+#[derive(Debug)]
+pub struct Error {
+    code: ErrorCode,
+    pub message: &'static str,
+    pub tip: &'static str,
+    etype: ErrorType,
+}
+
+impl Error {
+    pub(crate) fn new(code: ErrorCode, message: &'static str, tip: &'static str, etype: ErrorType) -> Self {
+        Self {
+            code,
+            message,
+            tip,
+            etype,
+        }
+    }
+
+    pub fn get_code(&self) -> Option<ErrorCode> {
+        if self.code == ErrorCode::None {
+            return None
+        }
+
+        Some(self.code)
+    }
+
+    pub fn get_type(&self) -> Option<ErrorType> {
+        if &self.etype == &ErrorType::None {
+            return None
+        }
+
+        Some(self.etype)
+    }
+}
+
+#[cfg(windows)]
+impl Error {
+    pub unsafe fn last_sys_error() -> u32 {
+        winapi::um::errhandlingapi::GetLastError()
+    }
+}
+
+#[cfg(unix)]
+impl Error {
+    pub fn last_errno() -> i32 {
+        std::io::Error::last_os_error().raw_os_error().unwrap_or(0)
+    }
+}
@@ -0,0 +1,13 @@
+/// The Windows mandatory integrity level of a token, as reported by
+/// `GetTokenInformation(TokenIntegrityLevel)`.
+///
+/// This is orthogonal to `ElevationType`: a token can be elevated and still sit at a
+/// non-`System` integrity level, and vice versa.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum IntegrityLevel {
+    Untrusted,
+    Low,
+    Medium,
+    High,
+    System,
+}
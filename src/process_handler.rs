@@ -1,6 +1,3 @@
-// Yet unused
-#![allow(dead_code)]
-
 use std::process;
 use sysinfo::{
     System,
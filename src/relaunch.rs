@@ -0,0 +1,160 @@
+use std::env;
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr::null_mut;
+
+use winapi::shared::minwindef::{FALSE, DWORD};
+use winapi::shared::winerror::ERROR_CANCELLED;
+use winapi::um::errhandlingapi::GetLastError;
+use winapi::um::handleapi::CloseHandle;
+use winapi::um::shellapi::{ShellExecuteExW, SHELLEXECUTEINFOW, SEE_MASK_NOCLOSEPROCESS};
+use winapi::um::synchapi::WaitForSingleObject;
+use winapi::um::winbase::INFINITE;
+use winapi::um::winuser::SW_SHOWNORMAL;
+
+use crate::error::{Error, ErrorCode, ErrorType};
+use crate::process_handler::ProcessHandler;
+use crate::sys::Elevation;
+
+fn to_wide(s: &str) -> Vec<u16> {
+    OsStr::new(s).encode_wide().chain(std::iter::once(0)).collect()
+}
+
+/// Quotes a single argument per the Windows command-line rules (the same ones
+/// `CommandLineToArgvW` uses to split them back apart), so that an argument containing spaces
+/// or embedded quotes survives the round trip through `ShellExecuteExW`'s `lpParameters`.
+fn quote_arg(arg: &str) -> String {
+    let needs_quotes = arg.is_empty() || arg.contains(' ') || arg.contains('\t') || arg.contains('"');
+
+    if !needs_quotes {
+        return arg.to_string()
+    }
+
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+
+    let mut backslashes = 0usize;
+    for c in arg.chars() {
+        if c == '\\' {
+            backslashes += 1;
+        } else {
+            if c == '"' {
+                for _ in 0..=backslashes {
+                    quoted.push('\\');
+                }
+            }
+            backslashes = 0;
+        }
+        quoted.push(c);
+    }
+
+    // Trailing backslashes must be doubled, since they'd otherwise escape the closing quote.
+    for _ in 0..backslashes {
+        quoted.push('\\');
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+fn quote_args(args: &[String]) -> String {
+    args.iter().map(|arg| quote_arg(arg)).collect::<Vec<_>>().join(" ")
+}
+
+/// Relaunches the current executable elevated, passing along the original command-line
+/// arguments. Does nothing and returns `Ok(true)` if `el` already reports admin rights.
+///
+/// ## Example
+/// ```rust,ignore
+/// use elevation_rs::Elevation;
+/// use elevation_rs::relaunch_elevated;
+///
+/// unsafe {
+///     let mut el = Elevation::new();
+///     match relaunch_elevated(&mut el) {
+///         Ok(true) => println!("Already elevated, or the user approved the UAC prompt"),
+///         Ok(false) => println!("User cancelled the UAC prompt"),
+///         Err(error) => println!("Error: {:#?}", error),
+///     }
+/// }
+/// ```
+pub unsafe fn relaunch_elevated(el: &mut Elevation) -> Result<bool, Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    relaunch_elevated_with_args(el, &args, false)
+}
+
+/// Same as `relaunch_elevated`, but lets the caller pass a specific argument list and choose
+/// whether to block until the elevated process exits.
+pub unsafe fn relaunch_elevated_with_args(el: &mut Elevation, args: &[String], wait: bool) -> Result<bool, Error> {
+    // `ElevationType::Full` alone isn't a safe "already admin" check: with UAC disabled, an
+    // administrator's token is `Default`, not `Full`, so gating on elevation_type() would
+    // unconditionally relaunch (and kill_self()) in a loop on every UAC-disabled machine.
+    // is_elevated() reflects actual Administrators-group membership of the running token.
+    if el.is_elevated(None)? {
+        return Ok(true)
+    }
+
+    let exe = env::current_exe()
+        .map_err(|e| Error::new(
+            ErrorCode::Sys(e.raw_os_error().unwrap_or(0) as u32),
+            "Failed to resolve the current executable's path",
+            "std::env::current_exe() failed; check the process's permissions.",
+            ErrorType::Unknown,
+        ))?;
+
+    let verb = to_wide("runas");
+    let file = to_wide(&exe.to_string_lossy());
+    let params = to_wide(&quote_args(args));
+
+    let mut info: SHELLEXECUTEINFOW = std::mem::zeroed();
+    info.cbSize = std::mem::size_of::<SHELLEXECUTEINFOW>() as DWORD;
+    info.fMask = SEE_MASK_NOCLOSEPROCESS;
+    info.hwnd = null_mut();
+    info.lpVerb = verb.as_ptr();
+    info.lpFile = file.as_ptr();
+    info.lpParameters = params.as_ptr();
+    info.nShow = SW_SHOWNORMAL;
+
+    if ShellExecuteExW(&mut info) == FALSE {
+        let code = GetLastError();
+
+        if code == ERROR_CANCELLED {
+            return Ok(false)
+        }
+
+        return Err(
+            Error::new(
+                ErrorCode::Sys(code),
+                "ShellExecuteExW has failed!",
+                "Check the error code for more details.",
+                ErrorType::ShellExecuteFailed,
+            )
+        )
+    }
+
+    if !info.hProcess.is_null() {
+        if wait {
+            WaitForSingleObject(info.hProcess, INFINITE);
+        }
+
+        CloseHandle(info.hProcess);
+    }
+
+    Ok(true)
+}
+
+/// Relaunches the current executable elevated and, if the user approved the UAC prompt,
+/// terminates this now-redundant de-elevated process via `ProcessHandler::kill_self()`.
+/// Returns without killing the caller if the prompt was cancelled.
+pub unsafe fn relaunch_elevated_and_exit(el: &mut Elevation) -> Result<(), Error> {
+    // relaunch_elevated() also returns Ok(true) without spawning anything when already elevated.
+    if el.is_elevated(None)? {
+        return Ok(())
+    }
+
+    if relaunch_elevated(el)? {
+        ProcessHandler::kill_self();
+    }
+
+    Ok(())
+}
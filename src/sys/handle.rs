@@ -0,0 +1,25 @@
+use winapi::um::handleapi::CloseHandle;
+use winapi::um::winnt::HANDLE;
+
+/// A `HANDLE` that closes itself on drop, so callers can't forget to (or double-) `CloseHandle` it.
+pub(crate) struct OwnedHandle(HANDLE);
+
+impl OwnedHandle {
+    /// Takes ownership of `handle`. `handle` must be a handle the caller owns (not a pseudo
+    /// handle such as the one returned by `GetCurrentProcess`), or null.
+    pub(crate) unsafe fn new(handle: HANDLE) -> Self {
+        Self(handle)
+    }
+
+    pub(crate) fn as_raw(&self) -> HANDLE {
+        self.0
+    }
+}
+
+impl Drop for OwnedHandle {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { CloseHandle(self.0); }
+        }
+    }
+}
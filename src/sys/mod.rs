@@ -0,0 +1,13 @@
+#[cfg(windows)]
+mod handle;
+#[cfg(windows)]
+mod sid;
+#[cfg(windows)]
+mod windows;
+#[cfg(windows)]
+pub use windows::Elevation;
+
+#[cfg(unix)]
+mod unix;
+#[cfg(unix)]
+pub use unix::Elevation;
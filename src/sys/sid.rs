@@ -0,0 +1,24 @@
+use winapi::um::securitybaseapi::FreeSid;
+use winapi::um::winnt::PSID;
+
+/// A `PSID` allocated by `AllocateAndInitializeSid`, freed via `FreeSid` on drop.
+pub(crate) struct OwnedSid(PSID);
+
+impl OwnedSid {
+    /// Takes ownership of `sid`. `sid` must have come from `AllocateAndInitializeSid`.
+    pub(crate) unsafe fn new(sid: PSID) -> Self {
+        Self(sid)
+    }
+
+    pub(crate) fn as_raw(&self) -> PSID {
+        self.0
+    }
+}
+
+impl Drop for OwnedSid {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { FreeSid(self.0); }
+        }
+    }
+}
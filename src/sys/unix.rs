@@ -0,0 +1,91 @@
+use std::fs;
+
+use libc;
+
+use crate::error::{Error, ErrorCode, ErrorType};
+
+
+/// Unix has no per-process token to allocate ahead of time, so `Elevation`
+/// carries no state here; it exists purely to mirror the Windows API shape.
+pub struct Elevation;
+
+impl Elevation {
+    /// Returns an Elevation structure instance
+    pub unsafe fn new() -> Self {
+        Self
+    }
+
+    /// Checks if certain process is elevated or not.
+    /// If `pid` is equal to `None`, is_elevated checks the effective UID of the calling process.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// use elevation_rs::Elevation;
+    ///
+    /// unsafe {
+    ///     let mut el = Elevation::new();
+    ///     match el.is_elevated(None) {
+    ///         Ok(result) => println!("Elevated: {}", result),
+    ///         Err(error) => println!("Error: {:#?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn is_elevated(&mut self, pid: Option<u32>) -> Result<bool, Error> {
+        match pid {
+            None => Ok(libc::geteuid() == 0),
+            Some(pid) => Elevation::is_elevated_pid(pid),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    unsafe fn is_elevated_pid(pid: u32) -> Result<bool, Error> {
+        let status = fs::read_to_string(format!("/proc/{}/status", pid))
+            .map_err(|e| Error::new(
+                ErrorCode::Errno(e.raw_os_error().unwrap_or(0)),
+                "Failed to read /proc/<pid>/status",
+                "Check that the process exists and that you have permission to inspect it.",
+                ErrorType::OpenProcessFailed,
+            ))?;
+
+        let euid = status
+            .lines()
+            .find_map(|line| line.strip_prefix("Uid:"))
+            .and_then(|cols| cols.split_whitespace().nth(1))
+            .and_then(|euid| euid.parse::<u32>().ok())
+            .ok_or_else(|| Error::new(
+                ErrorCode::None,
+                "Failed to parse the Uid: line in /proc/<pid>/status",
+                "The kernel's status format may have changed.",
+                ErrorType::Unknown,
+            ))?;
+
+        Ok(euid == 0)
+    }
+
+    /// macOS has no `/proc`; shelling out to `ps` is far simpler than linking
+    /// against libproc just to read one field out of `proc_bsdinfo`.
+    #[cfg(target_os = "macos")]
+    unsafe fn is_elevated_pid(pid: u32) -> Result<bool, Error> {
+        let output = std::process::Command::new("ps")
+            .args(&["-o", "uid=", "-p", &pid.to_string()])
+            .output()
+            .map_err(|e| Error::new(
+                ErrorCode::Errno(e.raw_os_error().unwrap_or(0)),
+                "Failed to spawn `ps` to read the target's effective UID",
+                "Check that the process exists and that you have permission to inspect it.",
+                ErrorType::OpenProcessFailed,
+            ))?;
+
+        let euid = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| Error::new(
+                ErrorCode::None,
+                "Failed to parse `ps`'s output as a UID",
+                "The process may have exited before `ps` could read it.",
+                ErrorType::Unknown,
+            ))?;
+
+        Ok(euid == 0)
+    }
+}
@@ -0,0 +1,283 @@
+use std::mem;
+use std::ptr::null_mut;
+
+use winapi::um::securitybaseapi::{
+    CheckTokenMembership, AllocateAndInitializeSid, GetTokenInformation,
+    GetSidSubAuthorityCount, GetSidSubAuthority, DuplicateToken,
+};
+use winapi::um::winnt::{
+    SECURITY_NT_AUTHORITY, SID_IDENTIFIER_AUTHORITY, PSID, SECURITY_BUILTIN_DOMAIN_RID, DOMAIN_ALIAS_RID_ADMINS,
+    PROCESS_QUERY_LIMITED_INFORMATION, TOKEN_QUERY, TOKEN_DUPLICATE, TOKEN_ELEVATION_TYPE,
+    TOKEN_MANDATORY_LABEL, TokenElevationType, TokenElevationTypeFull, TokenElevationTypeLimited,
+    TokenIntegrityLevel, SECURITY_MANDATORY_UNTRUSTED_RID, SECURITY_MANDATORY_LOW_RID, SECURITY_MANDATORY_MEDIUM_RID,
+    SECURITY_MANDATORY_HIGH_RID, SECURITY_MANDATORY_SYSTEM_RID, SecurityIdentification,
+};
+use winapi::um::winnt::HANDLE;
+use winapi::um::processthreadsapi::{OpenProcess, OpenProcessToken, GetCurrentProcess};
+use winapi::ctypes::c_void;
+use winapi::shared::minwindef::{FALSE, TRUE, DWORD};
+use winapi::um::errhandlingapi::GetLastError;
+
+use crate::error::{Error, ErrorCode, ErrorType};
+use crate::elevation_type::ElevationType;
+use crate::integrity_level::IntegrityLevel;
+use crate::sys::handle::OwnedHandle;
+use crate::sys::sid::OwnedSid;
+
+
+/// `sid_result: i32` - return value of the AllocateAndInitializeSid() function <br>
+/// `admins_group: OwnedSid` - security identifier of admins group <br>
+pub struct Elevation {
+    sid_result: i32,
+    admins_group: OwnedSid,
+}
+
+impl Elevation {
+    /// Returns an Elevation structure instance
+    pub unsafe fn new() -> Self {
+        let (sid_result, admins_group) = Elevation::alloc();
+
+        Self {
+            sid_result,
+            admins_group,
+        }
+    }
+
+    /// Allocates what is necessary <br>
+    /// Used in Elevation::new() <br>
+    /// It has been detached from Elevation::is_elevated() to prevent from crashes and unnecessary operations <br>
+    unsafe fn alloc() -> (i32, OwnedSid) {
+        let mut authority: SID_IDENTIFIER_AUTHORITY = Default::default();
+        authority.Value = SECURITY_NT_AUTHORITY;
+
+        let mut admins_group: PSID = null_mut();
+
+        let sid_result = AllocateAndInitializeSid(
+            &mut authority,
+            2,
+            SECURITY_BUILTIN_DOMAIN_RID,
+            DOMAIN_ALIAS_RID_ADMINS,
+            0, 0, 0, 0, 0, 0,
+            &mut admins_group
+        );
+
+        (sid_result, OwnedSid::new(admins_group))
+    }
+
+    /// Checks if certain process is elevated or not
+    /// If `pid` is equal to `None`, is_elevated uses the impersonation token of the calling thread.
+    /// If the thread is not impersonating, the function duplicates the thread's primary token to create an impersonation token.
+    /// If `pid` is `Some`, the target process's primary token is opened and duplicated into an
+    /// impersonation token, since `CheckTokenMembership` only accepts impersonation tokens (or `NULL`
+    /// for the calling thread), not a bare process handle.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// use elevation_rs::Elevation;
+    ///
+    /// unsafe {
+    ///     let mut el = Elevation::new();
+    ///     match el.is_elevated(None) {
+    ///         Ok(result) => println!("Elevated: {}", result),
+    ///         Err(error) => println!("Error: {:#?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn is_elevated(&mut self, pid: Option<u32>) -> Result<bool, Error> {
+        let mut impersonation_token: HANDLE = null_mut();
+        let mut impersonation_guard = None;
+
+        if pid.is_some() {
+            let process_token = Elevation::open_token(pid, TOKEN_QUERY | TOKEN_DUPLICATE)?;
+            let duplicated = DuplicateToken(process_token.as_raw(), SecurityIdentification, &mut impersonation_token);
+
+            if duplicated == FALSE {
+                return Err(
+                    Error::new(
+                        ErrorCode::Sys(GetLastError()),
+                        "DuplicateToken has failed!",
+                        "Check the error code for more details.",
+                        ErrorType::DuplicateTokenFailed,
+                    )
+                )
+            }
+
+            impersonation_guard = Some(OwnedHandle::new(impersonation_token));
+        }
+
+        // `sid_result` only records whether AllocateAndInitializeSid() succeeded in alloc();
+        // the actual membership result for *this* call is kept in a fresh local so repeated
+        // calls on the same Elevation never get stuck on a prior call's outcome.
+        let mut is_member = FALSE;
+
+        if self.sid_result == TRUE {
+            if CheckTokenMembership(impersonation_token, self.admins_group.as_raw(), &mut is_member) == FALSE {
+                is_member = FALSE
+            }
+        }
+
+        drop(impersonation_guard);
+
+        Ok(is_member == TRUE)
+    }
+
+    /// Opens the token of `pid`'s process with `access`, or of the current process if `pid` is `None`.
+    unsafe fn open_token(pid: Option<u32>, access: DWORD) -> Result<OwnedHandle, Error> {
+        let process_guard;
+
+        let process = match pid {
+            None => {
+                process_guard = None;
+                GetCurrentProcess()
+            }
+            Some(pid) => {
+                let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, FALSE, pid);
+
+                if process == null_mut() {
+                    return Err(
+                        Error::new(
+                            ErrorCode::Sys(GetLastError()),
+                            "OpenProcess has failed!",
+                            "Check the error code for more details.",
+                            ErrorType::OpenProcessFailed,
+                        )
+                    )
+                }
+
+                process_guard = Some(OwnedHandle::new(process));
+                process
+            }
+        };
+
+        let mut token: HANDLE = null_mut();
+        let opened = OpenProcessToken(process, access, &mut token);
+
+        drop(process_guard);
+
+        if opened == FALSE {
+            return Err(
+                Error::new(
+                    ErrorCode::Sys(GetLastError()),
+                    "OpenProcessToken has failed!",
+                    "Check the error code for more details.",
+                    ErrorType::OpenProcessTokenFailed,
+                )
+            )
+        }
+
+        Ok(OwnedHandle::new(token))
+    }
+
+    /// Queries the UAC elevation type of `pid`'s token, or of the current process's token if `pid` is `None`.
+    /// Unlike `is_elevated`, which only tells you whether the token is a member of Administrators,
+    /// this distinguishes "UAC disabled", "running elevated" and "running de-elevated with a linked
+    /// admin token available".
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// use elevation_rs::Elevation;
+    ///
+    /// unsafe {
+    ///     let mut el = Elevation::new();
+    ///     match el.elevation_type(None) {
+    ///         Ok(etype) => println!("Elevation type: {:?}", etype),
+    ///         Err(error) => println!("Error: {:#?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn elevation_type(&mut self, pid: Option<u32>) -> Result<ElevationType, Error> {
+        let token = Elevation::open_token(pid, TOKEN_QUERY)?;
+        let mut returned_len: DWORD = 0;
+
+        // TokenElevation (TokenIsElevated) can't be trusted to disambiguate Full from Default:
+        // with UAC disabled, an administrator's token reports TokenIsElevated = TRUE even though
+        // TokenElevationType is TokenElevationTypeDefault, since there's no split token at all.
+        // TokenElevationType alone is what tells us Default/Full/Limited correctly, so that's
+        // the only query we need here.
+        let mut elevation_type: TOKEN_ELEVATION_TYPE = 0;
+
+        let queried_type = GetTokenInformation(
+            token.as_raw(),
+            TokenElevationType,
+            &mut elevation_type as *mut _ as *mut c_void,
+            mem::size_of::<TOKEN_ELEVATION_TYPE>() as DWORD,
+            &mut returned_len,
+        );
+
+        if queried_type == FALSE {
+            return Err(
+                Error::new(
+                    ErrorCode::Sys(GetLastError()),
+                    "GetTokenInformation has failed!",
+                    "Check the error code for more details.",
+                    ErrorType::GetTokenInformationFailed,
+                )
+            )
+        }
+
+        Ok(match elevation_type {
+            TokenElevationTypeFull => ElevationType::Full,
+            TokenElevationTypeLimited => ElevationType::Limited,
+            _ => ElevationType::Default,
+        })
+    }
+
+    /// Queries the mandatory integrity level of `pid`'s token, or of the current process's
+    /// token if `pid` is `None`.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// use elevation_rs::Elevation;
+    ///
+    /// unsafe {
+    ///     let mut el = Elevation::new();
+    ///     match el.integrity_level(None) {
+    ///         Ok(level) => println!("Integrity level: {:?}", level),
+    ///         Err(error) => println!("Error: {:#?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn integrity_level(&mut self, pid: Option<u32>) -> Result<IntegrityLevel, Error> {
+        let token = Elevation::open_token(pid, TOKEN_QUERY)?;
+
+        // TokenIntegrityLevel is variable-length, so size the buffer from the first call's out-param.
+        let mut required_len: DWORD = 0;
+        GetTokenInformation(token.as_raw(), TokenIntegrityLevel, null_mut(), 0, &mut required_len);
+
+        let mut buffer = vec![0u8; required_len as usize];
+        let queried = GetTokenInformation(
+            token.as_raw(),
+            TokenIntegrityLevel,
+            buffer.as_mut_ptr() as *mut c_void,
+            required_len,
+            &mut required_len,
+        );
+
+        if queried == FALSE {
+            return Err(
+                Error::new(
+                    ErrorCode::Sys(GetLastError()),
+                    "GetTokenInformation has failed!",
+                    "Check the error code for more details.",
+                    ErrorType::GetTokenInformationFailed,
+                )
+            )
+        }
+
+        let label = buffer.as_ptr() as *const TOKEN_MANDATORY_LABEL;
+        let sid = (*label).Label.Sid;
+
+        let sub_authority_count = *GetSidSubAuthorityCount(sid);
+        let rid = *GetSidSubAuthority(sid, (sub_authority_count - 1) as DWORD);
+
+        Ok(match rid {
+            SECURITY_MANDATORY_SYSTEM_RID => IntegrityLevel::System,
+            SECURITY_MANDATORY_HIGH_RID => IntegrityLevel::High,
+            SECURITY_MANDATORY_MEDIUM_RID => IntegrityLevel::Medium,
+            SECURITY_MANDATORY_LOW_RID => IntegrityLevel::Low,
+            SECURITY_MANDATORY_UNTRUSTED_RID => IntegrityLevel::Untrusted,
+            _ => IntegrityLevel::Untrusted,
+        })
+    }
+
+}